@@ -0,0 +1,148 @@
+//! The `#[derive(Html)]` macro for [`html_gen`](https://docs.rs/html_gen).
+//!
+//! This crate is not meant to be used directly; depend on `html_gen` with the `derive`
+//! feature enabled instead.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derives [`Html`](html_gen::Html) for a struct with named fields.
+///
+/// By default, each field is rendered as `<span class="field-name">{value}</span>`, with
+/// `{value}` produced via `ToString` and escaped. Field behavior can be overridden with
+/// `#[html(...)]`:
+///
+/// - `#[html(attr)]` / `#[html(attr = "name")]` renders the field as an attribute on the
+///   wrapping element instead of a child.
+/// - `#[html(child)]` renders the field by calling its own [`Html`](html_gen::Html) impl
+///   instead of converting it to text.
+///
+/// The wrapping element defaults to `<div>`; override it with a container-level
+/// `#[html(tag = "article")]`.
+#[proc_macro_derive(Html, attributes(html))]
+pub fn derive_html(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    ident,
+                    "#[derive(Html)] only supports structs with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(ident, "#[derive(Html)] only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let tag = container_tag(&input.attrs).unwrap_or_else(|| "div".to_string());
+
+    let mut attr_writes = Vec::new();
+    let mut body_writes = Vec::new();
+
+    for field in fields {
+        let field_ident = field
+            .ident
+            .as_ref()
+            .expect("Fields::Named guarantees an identifier");
+
+        match field_mode(&field.attrs) {
+            FieldMode::Attr(name) => {
+                let attr_name = name.unwrap_or_else(|| field_ident.to_string());
+                attr_writes.push(quote! {
+                    write!(writer, " {}=\"", #attr_name)?;
+                    ::html_gen::escape::write_escaped_attribute(
+                        writer,
+                        &::std::string::ToString::to_string(&self.#field_ident),
+                    )?;
+                    write!(writer, "\"")?;
+                });
+            }
+            FieldMode::Child => {
+                body_writes.push(quote! {
+                    ::html_gen::Html::write_html(&self.#field_ident, writer)?;
+                });
+            }
+            FieldMode::Text => {
+                let class_name = field_ident.to_string().replace('_', "-");
+                body_writes.push(quote! {
+                    write!(writer, "<span class=\"{}\">", #class_name)?;
+                    ::html_gen::escape::write_escaped_text(
+                        writer,
+                        &::std::string::ToString::to_string(&self.#field_ident),
+                    )?;
+                    write!(writer, "</span>")?;
+                });
+            }
+        }
+    }
+
+    let expanded = quote! {
+        impl ::html_gen::Html for #ident {
+            fn write_html(&self, writer: &mut dyn ::std::fmt::Write) -> ::std::fmt::Result {
+                write!(writer, "<{}", #tag)?;
+                #(#attr_writes)*
+                write!(writer, ">")?;
+                #(#body_writes)*
+                write!(writer, "</{}>", #tag)
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+enum FieldMode {
+    Text,
+    Attr(Option<String>),
+    Child,
+}
+
+fn container_tag(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("html") {
+            return None;
+        }
+        let mut tag = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                tag = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            }
+            Ok(())
+        });
+        tag
+    })
+}
+
+fn field_mode(attrs: &[syn::Attribute]) -> FieldMode {
+    attrs
+        .iter()
+        .find_map(|attr| {
+            if !attr.path().is_ident("html") {
+                return None;
+            }
+            let mut mode = None;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("child") {
+                    mode = Some(FieldMode::Child);
+                } else if meta.path.is_ident("attr") {
+                    mode = Some(match meta.value() {
+                        Ok(value) => FieldMode::Attr(Some(value.parse::<syn::LitStr>()?.value())),
+                        Err(_) => FieldMode::Attr(None),
+                    });
+                }
+                Ok(())
+            });
+            mode
+        })
+        .unwrap_or(FieldMode::Text)
+}
@@ -0,0 +1,103 @@
+//! Helpers for building up the attributes (`id="..."`, `class="..."`, ...) attached to an
+//! HTML element.
+
+use crate::escape::write_escaped_attribute;
+use std::fmt;
+
+/// A deduplicated, order-preserving set of CSS class names, rendered as a single
+/// space-separated `class` attribute value.
+///
+/// Classes are stored in insertion order, and adding a class that's already present is a
+/// no-op rather than producing a duplicate token.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Classes {
+    names: Vec<String>,
+}
+
+impl Classes {
+    /// Creates a new, empty set of classes
+    pub fn new() -> Self {
+        Classes::default()
+    }
+
+    /// Adds a single class, ignoring it if it's already present
+    pub fn add(&mut self, class: impl Into<String>) -> &mut Self {
+        let class = class.into();
+        if !self.names.contains(&class) {
+            self.names.push(class);
+        }
+        self
+    }
+
+    /// Adds every class yielded by `classes`, ignoring any already present
+    pub fn extend(&mut self, classes: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
+        for class in classes {
+            self.add(class);
+        }
+        self
+    }
+
+    /// Writes this set as a ` class="..."` attribute, including the leading space.
+    ///
+    /// Writes nothing if the set is empty.
+    pub(crate) fn write_attribute(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        if self.names.is_empty() {
+            return Ok(());
+        }
+
+        writer.write_str(" class=\"")?;
+        for (i, name) in self.names.iter().enumerate() {
+            if i > 0 {
+                writer.write_str(" ")?;
+            }
+            write_escaped_attribute(writer, name)?;
+        }
+        writer.write_str("\"")
+    }
+}
+
+impl From<&str> for Classes {
+    fn from(class: &str) -> Self {
+        let mut classes = Classes::new();
+        classes.add(class);
+        classes
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Classes {
+    fn from(classes: [&str; N]) -> Self {
+        let mut set = Classes::new();
+        set.extend(classes);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod classes {
+        use super::*;
+
+        #[test]
+        fn dedupes_repeated_classes() {
+            let mut sut = Classes::new();
+            sut.add("foo").add("bar").add("foo");
+
+            let mut rendered = String::new();
+            sut.write_attribute(&mut rendered).unwrap();
+
+            assert_eq!(rendered, " class=\"foo bar\"");
+        }
+
+        #[test]
+        fn empty_set_renders_nothing() {
+            let sut = Classes::new();
+
+            let mut rendered = String::new();
+            sut.write_attribute(&mut rendered).unwrap();
+
+            assert_eq!(rendered, "");
+        }
+    }
+}
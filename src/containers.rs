@@ -0,0 +1,194 @@
+//! Containers are elements which may hold other pieces of HTML, such as a `<div>` or an
+//! `<article>`. They are the main building blocks used to compose a page.
+
+use crate::attributes::Classes;
+use crate::content::{HtmlElement, RawHtml};
+use crate::Html;
+use std::fmt;
+
+/// The tag used to wrap a [`Container`]'s children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerType {
+    Article,
+    Div,
+    Footer,
+    Header,
+    Main,
+    Nav,
+    OrderedList,
+    Section,
+    UnorderedList,
+}
+
+impl ContainerType {
+    fn tag(self) -> &'static str {
+        match self {
+            ContainerType::Article => "article",
+            ContainerType::Div => "div",
+            ContainerType::Footer => "footer",
+            ContainerType::Header => "header",
+            ContainerType::Main => "main",
+            ContainerType::Nav => "nav",
+            ContainerType::OrderedList => "ol",
+            ContainerType::Section => "section",
+            ContainerType::UnorderedList => "ul",
+        }
+    }
+}
+
+/// An element which can hold other [`Html`] elements, built up via chained `add_*` calls.
+#[derive(Debug)]
+pub struct Container {
+    container_type: ContainerType,
+    elements: Vec<Box<dyn Html>>,
+    classes: Classes,
+}
+
+impl Container {
+    /// Creates a new, empty container of the given type
+    pub fn new(container_type: ContainerType) -> Self {
+        Container {
+            container_type,
+            elements: Vec::new(),
+            classes: Classes::new(),
+        }
+    }
+
+    /// Adds a single CSS class to this container's `class` attribute, ignoring it if it's
+    /// already present
+    pub fn add_class(mut self, class: impl Into<String>) -> Self {
+        self.classes.add(class);
+        self
+    }
+
+    /// Adds every class yielded by `classes` to this container's `class` attribute,
+    /// ignoring any already present
+    pub fn with_classes(mut self, classes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.classes.extend(classes);
+        self
+    }
+}
+
+impl Html for Container {
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        let tag = self.container_type.tag();
+        write!(writer, "<{}", tag)?;
+        self.classes.write_attribute(writer)?;
+        writer.write_str(">")?;
+        for element in &self.elements {
+            element.write_html(writer)?;
+        }
+        write!(writer, "</{}>", tag)
+    }
+
+    fn write_html_indented(
+        &self,
+        writer: &mut dyn fmt::Write,
+        config: &crate::PrettyConfig,
+        depth: usize,
+    ) -> fmt::Result {
+        let tag = self.container_type.tag();
+        crate::pretty::write_indent(writer, config, depth)?;
+        write!(writer, "<{}", tag)?;
+        self.classes.write_attribute(writer)?;
+        writer.write_str(">")?;
+        if self.elements.is_empty() {
+            return write!(writer, "</{}>", tag);
+        }
+        writer.write_str("\n")?;
+        for element in &self.elements {
+            element.write_html_indented(writer, config, depth + 1)?;
+            writer.write_str("\n")?;
+        }
+        crate::pretty::write_indent(writer, config, depth)?;
+        write!(writer, "</{}>", tag)
+    }
+}
+
+impl HtmlContainer for Container {
+    fn add_html(mut self, html: Box<dyn Html>) -> Self {
+        self.elements.push(html);
+        self
+    }
+}
+
+/// Shared ergonomics for anything that can have [`Html`] elements added to it, such as
+/// [`Container`] or [`crate::HtmlPage`].
+pub trait HtmlContainer: Sized {
+    /// Adds an arbitrary element to this container
+    fn add_html(self, html: Box<dyn Html>) -> Self;
+
+    /// Adds a header (`<h1>` through `<h6>`) with the given text
+    fn add_header(self, level: u8, text: &str) -> Self {
+        self.add_html(Box::new(HtmlElement::new(format!("h{}", level), text)))
+    }
+
+    /// Adds a paragraph (`<p>`) with the given text
+    fn add_paragraph(self, text: &str) -> Self {
+        self.add_html(Box::new(HtmlElement::new("p", text)))
+    }
+
+    /// Adds a nested container
+    fn add_container(self, container: Container) -> Self {
+        self.add_html(Box::new(container))
+    }
+
+    /// Adds a string of pre-rendered HTML without escaping it.
+    ///
+    /// Unlike the other `add_*` methods, the input is trusted verbatim, so only pass content
+    /// that is already known to be safe markup.
+    fn add_html_raw(self, html: impl Into<String>) -> Self {
+        self.add_html(Box::new(RawHtml(html.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod container {
+        use super::*;
+
+        #[test]
+        fn escapes_text_content() {
+            let sut = Container::new(ContainerType::Div).add_paragraph("<script>");
+
+            assert_eq!(sut.to_html_string(), "<div><p>&lt;script&gt;</p></div>");
+        }
+
+        #[test]
+        fn add_html_raw_is_not_escaped() {
+            let sut = Container::new(ContainerType::Div).add_html_raw("<b>hi</b>");
+
+            assert_eq!(sut.to_html_string(), "<div><b>hi</b></div>");
+        }
+
+        #[test]
+        fn add_class_deduplicates_across_calls() {
+            let sut = Container::new(ContainerType::Div)
+                .add_class("card")
+                .add_class("card");
+
+            assert_eq!(sut.to_html_string(), "<div class=\"card\"></div>");
+        }
+
+        #[test]
+        fn with_classes_accepts_an_array() {
+            let sut = Container::new(ContainerType::Div).with_classes(["card", "highlight"]);
+
+            assert_eq!(sut.to_html_string(), "<div class=\"card highlight\"></div>");
+        }
+
+        #[test]
+        fn pretty_print_indents_nested_children() {
+            let sut = Container::new(ContainerType::Article)
+                .add_header(2, "Hello, World")
+                .add_paragraph("This is a simple HTML demo");
+
+            assert_eq!(
+                sut.to_html_string_pretty(),
+                "<article>\n    <h2>Hello, World</h2>\n    <p>This is a simple HTML demo</p>\n</article>"
+            );
+        }
+    }
+}
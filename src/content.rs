@@ -0,0 +1,65 @@
+//! Content types that can be nested inside a [`crate::containers::Container`] or
+//! [`crate::HtmlPage`], such as headers, paragraphs, and the page title.
+
+use crate::escape::write_escaped_text;
+use crate::Html;
+use std::fmt;
+
+/// Content that may only appear inside the `<head>` of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadContent {
+    /// The page's `<title>` element
+    Title { content: String },
+}
+
+impl Html for HeadContent {
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        match self {
+            HeadContent::Title { content } => {
+                writer.write_str("<title>")?;
+                write_escaped_text(writer, content)?;
+                writer.write_str("</title>")
+            }
+        }
+    }
+}
+
+/// Wraps a string of pre-rendered HTML so it can be inserted into a container or page without
+/// being escaped.
+///
+/// This is an explicit escape hatch for callers who already have trusted, sanitized markup to
+/// inject; see [`crate::containers::HtmlContainer::add_html_raw`]. Prefer the regular
+/// `add_*` methods, which escape their input, whenever the content isn't already HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawHtml(pub String);
+
+impl Html for RawHtml {
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        writer.write_str(&self.0)
+    }
+}
+
+/// A generic, single-tag piece of text content such as a header or paragraph,
+/// e.g. `<h1>Hello</h1>` or `<p>Some text</p>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlElement {
+    tag: String,
+    content: String,
+}
+
+impl HtmlElement {
+    pub fn new(tag: impl Into<String>, content: impl Into<String>) -> Self {
+        HtmlElement {
+            tag: tag.into(),
+            content: content.into(),
+        }
+    }
+}
+
+impl Html for HtmlElement {
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        write!(writer, "<{}>", self.tag)?;
+        write_escaped_text(writer, &self.content)?;
+        write!(writer, "</{}>", self.tag)
+    }
+}
@@ -0,0 +1,35 @@
+//! Escaping of user-provided strings so they cannot break out of the surrounding markup.
+//!
+//! Text content and attribute values are escaped by default everywhere in this crate; use
+//! [`crate::content::RawHtml`] when pre-rendered markup genuinely needs to bypass this.
+
+use std::fmt;
+
+/// Writes `text` into `writer`, escaping the characters with special meaning in HTML text
+/// content (`&`, `<`, `>`).
+pub fn write_escaped_text(writer: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '&' => writer.write_str("&amp;")?,
+            '<' => writer.write_str("&lt;")?,
+            '>' => writer.write_str("&gt;")?,
+            c => writer.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+/// As [`write_escaped_text`], but additionally escapes `"` so the result is safe to embed
+/// inside a double-quoted attribute value.
+pub fn write_escaped_attribute(writer: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '"' => writer.write_str("&quot;")?,
+            '&' => writer.write_str("&amp;")?,
+            '<' => writer.write_str("&lt;")?,
+            '>' => writer.write_str("&gt;")?,
+            c => writer.write_char(c)?,
+        }
+    }
+    Ok(())
+}
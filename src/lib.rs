@@ -40,17 +40,89 @@
 
 use content::HeadContent;
 use std::fmt::{self, Display};
+use std::io;
 
 pub use containers::{Container, ContainerType, HtmlContainer};
+pub use content::RawHtml;
+
+/// Enables `#[derive(Html)]` for structs; see `html_gen_derive` for the generated code.
+#[cfg(feature = "derive")]
+pub use html_gen_derive::Html;
+
+pub use pretty::PrettyConfig;
+pub use template::{RenderedTemplate, Template, Value};
 
 mod attributes;
 pub mod containers;
 mod content;
 
+/// Escaping helpers used by the generated code of `#[derive(Html)]`.
+///
+/// Not part of the crate's public API otherwise; use the `add_*` methods on
+/// [`HtmlContainer`], which already escape their input.
+#[doc(hidden)]
+pub mod escape;
+mod pretty;
+mod template;
+
 /// An element that can be converted to HTML
 pub trait Html: fmt::Debug {
+    /// Writes this element's HTML representation into `writer`.
+    ///
+    /// Implementors should write directly into the shared buffer rather than building up
+    /// intermediate `String`s, so that rendering a whole page costs one growing allocation
+    /// instead of one per node.
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result;
+
     /// Convert this element into an HTML string
-    fn to_html_string(&self) -> String;
+    ///
+    /// This is a thin wrapper around [`Html::write_html`] provided for convenience and
+    /// backward compatibility.
+    fn to_html_string(&self) -> String {
+        let mut buf = String::new();
+        // `write_html` only fails if the underlying `fmt::Write` impl fails, and `String`'s
+        // never does.
+        self.write_html(&mut buf).expect("write to String cannot fail");
+        buf
+    }
+
+    /// Writes this element's HTML representation straight into `w`, e.g. a socket or file,
+    /// without building up an intermediate `String`.
+    fn write_to(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        struct Streamed<'a, T: ?Sized>(&'a T);
+
+        impl<T: Html + ?Sized> Display for Streamed<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.write_html(f)
+            }
+        }
+
+        write!(w, "{}", Streamed(self))
+    }
+
+    /// Writes this element's HTML representation into `writer`, indented for readability.
+    ///
+    /// `depth` is the current nesting level; implementors that hold children should
+    /// recurse at `depth + 1`. Elements with no children (text, raw HTML, ...) can rely on
+    /// this default, which just indents the single-line output from [`Html::write_html`].
+    fn write_html_indented(
+        &self,
+        writer: &mut dyn fmt::Write,
+        config: &PrettyConfig,
+        depth: usize,
+    ) -> fmt::Result {
+        pretty::write_indent(writer, config, depth)?;
+        self.write_html(writer)
+    }
+
+    /// Convert this element into an indented, multi-line HTML string, handy for debugging a
+    /// generated page. Use [`Html::to_html_string`] for compact, production output.
+    fn to_html_string_pretty(&self) -> String {
+        let mut buf = String::new();
+        self.write_html_indented(&mut buf, &PrettyConfig::default(), 0)
+            .expect("write to String cannot fail");
+        buf
+    }
 }
 
 /// This struct represents an entire page of HTML which can built up by chaining addition methods.
@@ -62,26 +134,78 @@ pub trait Html: fmt::Debug {
 pub struct HtmlPage {
     head: Vec<Box<dyn Html>>,
     body: Vec<Box<dyn Html>>,
+    body_classes: attributes::Classes,
 }
 
 impl Html for HtmlPage {
-    fn to_html_string(&self) -> String {
-        let head = self
-            .head
-            .iter()
-            .map(|element| element.to_html_string())
-            .fold(String::new(), |acc, next| acc + &next);
-        let body = self
-            .body
-            .iter()
-            .map(|element| element.to_html_string())
-            .fold(String::new(), |acc, next| acc + &next);
-
-        format!(
-            "<!DOCTYPE html><html><head>{}</head><body>{}</body></html>",
-            head, body
-        )
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        writer.write_str("<!DOCTYPE html><html><head>")?;
+        for element in &self.head {
+            element.write_html(writer)?;
+        }
+        writer.write_str("</head><body")?;
+        self.body_classes.write_attribute(writer)?;
+        writer.write_str(">")?;
+        for element in &self.body {
+            element.write_html(writer)?;
+        }
+        writer.write_str("</body></html>")
+    }
+
+    fn write_html_indented(
+        &self,
+        writer: &mut dyn fmt::Write,
+        config: &PrettyConfig,
+        depth: usize,
+    ) -> fmt::Result {
+        pretty::write_indent(writer, config, depth)?;
+        writer.write_str("<!DOCTYPE html>\n")?;
+        pretty::write_indent(writer, config, depth)?;
+        writer.write_str("<html>\n")?;
+
+        write_indented_block(writer, config, depth + 1, "head", None, &self.head)?;
+        writer.write_str("\n")?;
+        write_indented_block(
+            writer,
+            config,
+            depth + 1,
+            "body",
+            Some(&self.body_classes),
+            &self.body,
+        )?;
+        writer.write_str("\n")?;
+
+        pretty::write_indent(writer, config, depth)?;
+        writer.write_str("</html>")
+    }
+}
+
+/// Writes a `<tag>...</tag>` block with its children each on their own, further-indented
+/// line, shared by [`HtmlPage`]'s `<head>` and `<body>`.
+fn write_indented_block(
+    writer: &mut dyn fmt::Write,
+    config: &PrettyConfig,
+    depth: usize,
+    tag: &str,
+    classes: Option<&attributes::Classes>,
+    children: &[Box<dyn Html>],
+) -> fmt::Result {
+    pretty::write_indent(writer, config, depth)?;
+    write!(writer, "<{}", tag)?;
+    if let Some(classes) = classes {
+        classes.write_attribute(writer)?;
+    }
+    writer.write_str(">")?;
+    if children.is_empty() {
+        return write!(writer, "</{}>", tag);
     }
+    writer.write_str("\n")?;
+    for child in children {
+        child.write_html_indented(writer, config, depth + 1)?;
+        writer.write_str("\n")?;
+    }
+    pretty::write_indent(writer, config, depth)?;
+    write!(writer, "</{}>", tag)
 }
 
 impl HtmlContainer for HtmlPage {
@@ -109,6 +233,7 @@ impl HtmlPage {
         HtmlPage {
             head: Vec::new(),
             body: Vec::new(),
+            body_classes: attributes::Classes::new(),
         }
     }
 
@@ -120,6 +245,20 @@ impl HtmlPage {
         self.head.push(Box::new(title));
         self
     }
+
+    /// Adds a single CSS class to this page's `<body>` element, ignoring it if it's already
+    /// present
+    pub fn add_class(mut self, class: impl Into<String>) -> Self {
+        self.body_classes.add(class);
+        self
+    }
+
+    /// Adds every class yielded by `classes` to this page's `<body>` element, ignoring any
+    /// already present
+    pub fn with_classes(mut self, classes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body_classes.extend(classes);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -143,5 +282,55 @@ mod tests {
                 "<!DOCTYPE html><html><head></head><body></body></html>"
             )
         }
+
+        #[test]
+        fn add_class_dedupes_across_calls() {
+            // Arrange
+            let sut = HtmlPage::new().add_class("dark").add_class("dark");
+
+            // Act
+            let html_string = sut.to_html_string();
+
+            // Assert
+            assert_eq!(
+                html_string,
+                "<!DOCTYPE html><html><head></head><body class=\"dark\"></body></html>"
+            )
+        }
+
+        #[test]
+        fn pretty_print_matches_crate_doc_example() {
+            // Arrange
+            let sut = HtmlPage::new()
+                .add_title("My Page")
+                .add_header(1, "Main Content:")
+                .add_container(
+                    Container::new(ContainerType::Article)
+                        .add_header(2, "Hello, World")
+                        .add_paragraph("This is a simple HTML demo"),
+                );
+
+            // Act
+            let html_string = sut.to_html_string_pretty();
+
+            // Assert
+            let expected = [
+                "<!DOCTYPE html>",
+                "<html>",
+                "    <head>",
+                "        <title>My Page</title>",
+                "    </head>",
+                "    <body>",
+                "        <h1>Main Content:</h1>",
+                "        <article>",
+                "            <h2>Hello, World</h2>",
+                "            <p>This is a simple HTML demo</p>",
+                "        </article>",
+                "    </body>",
+                "</html>",
+            ]
+            .join("\n");
+            assert_eq!(html_string, expected)
+        }
     }
 }
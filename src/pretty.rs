@@ -0,0 +1,30 @@
+//! Pretty-printing support: indented, multi-line rendering of a document for debugging,
+//! as an alternative to the compact single-line output `write_html` produces.
+
+use std::fmt;
+
+/// Configures how [`crate::Html::write_html_indented`] indents nested elements.
+/// [`crate::Html::to_html_string_pretty`] always uses the default of 4 spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrettyConfig {
+    /// Number of spaces added per level of nesting
+    pub indent: usize,
+}
+
+impl Default for PrettyConfig {
+    fn default() -> Self {
+        PrettyConfig { indent: 4 }
+    }
+}
+
+/// Writes `depth * config.indent` spaces into `writer`.
+pub(crate) fn write_indent(
+    writer: &mut dyn fmt::Write,
+    config: &PrettyConfig,
+    depth: usize,
+) -> fmt::Result {
+    for _ in 0..depth * config.indent {
+        writer.write_char(' ')?;
+    }
+    Ok(())
+}
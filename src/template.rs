@@ -0,0 +1,141 @@
+//! Lightweight placeholder substitution for mixing pre-authored HTML fragments (headers,
+//! footers, ...) with the programmatic builder.
+
+use crate::escape::write_escaped_text;
+use crate::Html;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A value that can be substituted into a [`Template`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Int(i64),
+}
+
+impl Value {
+    fn to_text(&self) -> String {
+        match self {
+            Value::String(s) => s.clone(),
+            Value::Int(i) => i.to_string(),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+/// A string containing `[ name ]`-style placeholders, rendered by substituting each one with
+/// an escaped value from a `HashMap<String, Value>`.
+///
+/// A placeholder with no matching entry in the map is left untouched rather than causing an
+/// error; a map entry with no matching placeholder is simply never looked up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: String,
+}
+
+impl Template {
+    /// Creates a new template from its source text
+    pub fn new(source: impl Into<String>) -> Self {
+        Template {
+            source: source.into(),
+        }
+    }
+
+    /// Substitutes each `[ name ]` placeholder in this template with its escaped value from
+    /// `vars`
+    pub fn render(&self, vars: &HashMap<String, Value>) -> RenderedTemplate {
+        let mut rendered = String::with_capacity(self.source.len());
+        let mut rest = self.source.as_str();
+
+        while let Some(start) = rest.find('[') {
+            let Some(len) = rest[start..].find(']') else {
+                break;
+            };
+            let end = start + len;
+            let name = rest[start + 1..end].trim();
+
+            rendered.push_str(&rest[..start]);
+            match vars.get(name) {
+                Some(value) => write_escaped_text(&mut rendered, &value.to_text())
+                    .expect("write to String cannot fail"),
+                None => rendered.push_str(&rest[start..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        rendered.push_str(rest);
+
+        RenderedTemplate(rendered)
+    }
+}
+
+/// The result of [`Template::render`]; implements [`Html`] so it can be dropped straight
+/// into an [`crate::HtmlPage`] or [`crate::Container`] via `add_html`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedTemplate(String);
+
+impl Html for RenderedTemplate {
+    fn write_html(&self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        writer.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod template {
+        use super::*;
+
+        #[test]
+        fn substitutes_known_placeholders() {
+            let sut = Template::new("<p>Hi [ name ], you are #[ age ]</p>");
+            let mut vars = HashMap::new();
+            vars.insert("name".to_string(), Value::from("<Ada>"));
+            vars.insert("age".to_string(), Value::from(30_i64));
+
+            let rendered = sut.render(&vars);
+
+            assert_eq!(
+                rendered.to_html_string(),
+                "<p>Hi &lt;Ada&gt;, you are #30</p>"
+            );
+        }
+
+        #[test]
+        fn leaves_unknown_placeholders_untouched() {
+            let sut = Template::new("<p>Hi [ name ]</p>");
+
+            let rendered = sut.render(&HashMap::new());
+
+            assert_eq!(rendered.to_html_string(), "<p>Hi [ name ]</p>");
+        }
+
+        #[test]
+        fn ignores_unused_vars() {
+            let sut = Template::new("<p>no placeholders here</p>");
+            let mut vars = HashMap::new();
+            vars.insert("unused".to_string(), Value::from("ignored"));
+
+            let rendered = sut.render(&vars);
+
+            assert_eq!(rendered.to_html_string(), "<p>no placeholders here</p>");
+        }
+    }
+}